@@ -0,0 +1,64 @@
+use rayon::prelude::*;
+
+use crate::{Matrix, Point, Polygon};
+
+pub(crate) struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Canvas {
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Canvas {
+            width,
+            height,
+            pixels: vec![[0, 0, 0]; width * height],
+        }
+    }
+
+    /// Scanline-fills `poly` (mapped to pixel space through `viewport`) using
+    /// the even-odd rule. Per-row span computation is independent across
+    /// rows, so it's farmed out to rayon for large canvases.
+    pub(crate) fn fill_polygon(&mut self, poly: &Polygon, color: [u8; 3], viewport: &Matrix) {
+        let points: Vec<Point> = poly.points().map(|p| viewport * p).collect();
+        let width = self.width;
+
+        let spans: Vec<(usize, usize, usize)> = (0..self.height)
+            .into_par_iter()
+            .flat_map_iter(|y| {
+                let scan_y = y as f64 + 0.5;
+                let mut xs: Vec<f64> = Vec::new();
+                for i in 0..points.len() {
+                    let p0 = points[i];
+                    let p1 = points[(i + 1) % points.len()];
+                    if (p0.y <= scan_y) != (p1.y <= scan_y) {
+                        xs.push(p0.x + (scan_y - p0.y) / (p1.y - p0.y) * (p1.x - p0.x));
+                    }
+                }
+                xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                xs.chunks_exact(2)
+                    .filter_map(move |pair| {
+                        let x0 = pair[0].max(0.0) as usize;
+                        let x1 = (pair[1].max(0.0) as usize).min(width);
+                        (x0 < x1).then_some((y, x0, x1))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        for (y, x0, x1) in spans {
+            self.pixels[y * self.width + x0..y * self.width + x1].fill(color);
+        }
+    }
+
+    /// Encodes the framebuffer as a binary P6 PPM image.
+    pub(crate) fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for pixel in &self.pixels {
+            out.extend_from_slice(pixel);
+        }
+        out
+    }
+}
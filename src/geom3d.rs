@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+use crate::{Point, Polygon};
+
+#[derive(Clone, Copy)]
+pub(crate) struct Vect3 {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) z: f64,
+}
+
+impl Vect3 {
+    pub(crate) fn is_zero(self) -> bool {
+        self.x == 0.0 && self.y == 0.0 && self.z == 0.0
+    }
+    pub(crate) fn dot(self, rhs: Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+    pub(crate) fn cross(self, rhs: Self) -> Self {
+        Vect3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+    pub(crate) fn norm(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+    pub(crate) fn unit(self) -> Self {
+        if self.is_zero() {
+            panic!("unit undefined for the zero vector");
+        }
+        self / self.norm()
+    }
+}
+
+impl std::ops::Sub for Vect3 {
+    type Output = Vect3;
+    fn sub(self, rhs: Vect3) -> Self::Output {
+        Vect3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl std::ops::Div<f64> for Vect3 {
+    type Output = Vect3;
+    fn div(self, rhs: f64) -> Self::Output {
+        Vect3 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct Point3 {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) z: f64,
+}
+
+impl std::ops::Sub for Point3 {
+    type Output = Vect3;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vect3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+/// A planar face of a `Polyhedron`, given as indices into its vertex list
+/// plus the outward normal, cached so callers don't recompute it per query.
+pub(crate) struct Face {
+    pub(crate) indices: Vec<usize>,
+    pub(crate) normal: Vect3,
+}
+
+impl Face {
+    pub(crate) fn new(vertices: &[Point3], indices: Vec<usize>) -> Self {
+        let p0 = vertices[indices[0]];
+        let p1 = vertices[indices[1]];
+        let p2 = vertices[indices[2]];
+        let normal = (p1 - p0).cross(p2 - p0).unit();
+        Face { indices, normal }
+    }
+}
+
+pub(crate) struct Polyhedron {
+    pub(crate) vertices: Vec<Point3>,
+    pub(crate) faces: Vec<Face>,
+}
+
+impl Polyhedron {
+    /// Projects a face into the 2D `Polygon` type by building an orthonormal
+    /// basis from its normal and leading edge, so the existing flip/tiling
+    /// machinery can lay out polyhedral nets.
+    pub(crate) fn unfold_face(&self, face: usize) -> Polygon<'static> {
+        let face = &self.faces[face];
+        let origin = self.vertices[face.indices[0]];
+        let u = (self.vertices[face.indices[1]] - origin).unit();
+        let v = face.normal.cross(u);
+
+        let points = face
+            .indices
+            .iter()
+            .map(|&i| {
+                let p = self.vertices[i] - origin;
+                Point {
+                    x: p.dot(u),
+                    y: p.dot(v),
+                }
+            })
+            .collect();
+
+        Polygon::new(Cow::Owned(points))
+    }
+}
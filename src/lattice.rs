@@ -0,0 +1,96 @@
+//! Integer-coordinate counterparts to `Point`/`Vect`. Reflections that map
+//! lattice points to lattice points (e.g. the 90deg/120deg rotations behind
+//! regular tilings, expressed as integer matrices) stay exact across
+//! repeated `transform` calls, where the `f64` versions accumulate drift.
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct IVect {
+    pub(crate) x: i64,
+    pub(crate) y: i64,
+}
+
+impl IVect {
+    pub(crate) fn dot(self, rhs: Self) -> i64 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+    /// Applies the 2x2 integer matrix `[[m[0], m[1]], [m[2], m[3]]]`.
+    pub(crate) fn transform(self, m: &[i64; 4]) -> Self {
+        IVect {
+            x: m[0] * self.x + m[1] * self.y,
+            y: m[2] * self.x + m[3] * self.y,
+        }
+    }
+    pub(crate) fn integral_norm(self) -> u64 {
+        integer_sqrt(self.dot(self) as u64)
+    }
+}
+
+impl std::ops::Sub for IVect {
+    type Output = IVect;
+    fn sub(self, rhs: Self) -> Self::Output {
+        IVect {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl std::ops::Add for IVect {
+    type Output = IVect;
+    fn add(self, rhs: Self) -> Self::Output {
+        IVect {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct IPoint {
+    pub(crate) x: i64,
+    pub(crate) y: i64,
+}
+
+impl IPoint {
+    pub(crate) fn transform(self, m: &[i64; 4]) -> Self {
+        IPoint {
+            x: m[0] * self.x + m[1] * self.y,
+            y: m[2] * self.x + m[3] * self.y,
+        }
+    }
+}
+
+impl std::ops::Sub for IPoint {
+    type Output = IVect;
+    fn sub(self, rhs: Self) -> Self::Output {
+        IVect {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl std::ops::Add<IVect> for IPoint {
+    type Output = IPoint;
+    fn add(self, rhs: IVect) -> Self::Output {
+        IPoint {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+/// Integer square root via Newton iteration, rounding down.
+fn integer_sqrt(sqr: u64) -> u64 {
+    if sqr == 0 {
+        return 0;
+    }
+    let mut guess = sqr;
+    loop {
+        let next = (guess + sqr / guess) / 2;
+        if next >= guess {
+            return guess;
+        }
+        guess = next;
+    }
+}
@@ -1,9 +1,14 @@
 use std::borrow::Cow;
 
+mod canvas;
+mod geom3d;
+mod lattice;
+mod tiling;
+
 #[derive(Clone, Copy)]
-struct Vect {
-    x: f64,
-    y: f64,
+pub(crate) struct Vect {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
 }
 
 impl Vect {
@@ -23,7 +28,7 @@ impl Vect {
         self / self.norm()
     }
     fn onto(self, other: Self) -> Self {
-        self.dot(other.unit()) * other
+        self.dot(other.unit()) * other.unit()
     }
 }
 
@@ -88,9 +93,9 @@ impl std::ops::Sub for Vect {
 }
 
 #[derive(Clone, Copy)]
-struct Point {
-    x: f64,
-    y: f64,
+pub(crate) struct Point {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
 }
 
 impl Default for Point {
@@ -132,7 +137,7 @@ impl std::ops::Sub<Vect> for Point {
     }
 }
 
-struct PointIter<'a> {
+pub(crate) struct PointIter<'a> {
     first: &'a [Point],
     second: &'a [Point],
 }
@@ -152,34 +157,74 @@ impl<'a> Iterator for PointIter<'a> {
     }
 }
 
-struct Polygon<'a> {
+pub(crate) struct Polygon<'a> {
     orientation: usize,
     points: Cow<'a, Vec<Point>>,
 }
 
 impl<'a> Polygon<'a> {
-    fn new(points: Cow<'a, Vec<Point>>) -> Self {
+    pub(crate) fn new(points: Cow<'a, Vec<Point>>) -> Self {
         Polygon {
             orientation: 0,
             points,
         }
     }
-    fn len(&self) -> usize {
+    pub(crate) fn len(&self) -> usize {
         self.points.as_ref().len()
     }
-    fn align(&self, change: isize) -> Self {
+    pub(crate) fn align(&self, change: isize) -> Self {
         Polygon {
             orientation: (self.orientation as isize + change) as usize,
             points: self.points.clone(),
         }
     }
-    fn points(&self) -> PointIter {
-        let (first, second) = self.points.as_ref().as_slice().split_at(self.orientation);
+    pub(crate) fn points(&self) -> PointIter {
+        let (before, after) = self.points.as_ref().as_slice().split_at(self.orientation);
         PointIter {
-            first, second
+            first: after,
+            second: before,
+        }
+    }
+    /// Shoelace-formula area, positive for counter-clockwise point order.
+    fn signed_area(&self) -> f64 {
+        let mut points = self.points();
+        let Some(first) = points.next() else {
+            return 0.0;
+        };
+        let mut prev = first;
+        let mut sum = 0.0;
+        for p in points {
+            sum += prev.x * p.y - p.x * prev.y;
+            prev = p;
         }
+        sum += prev.x * first.y - first.x * prev.y;
+        sum / 2.0
     }
-    fn flip_rotate(&self) -> Polygon {
+    fn is_ccw(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+    /// Ray-casting point-in-polygon test, with the usual half-open
+    /// `[y_i, y_j)` edge convention so points on a shared vertex aren't
+    /// counted twice.
+    fn contains(&self, p: Point) -> bool {
+        let mut points = self.points();
+        let Some(first) = points.next() else {
+            return false;
+        };
+        let mut prev = first;
+        let mut inside = false;
+        for curr in points.chain(std::iter::once(first)) {
+            if (prev.y <= p.y) != (curr.y <= p.y) {
+                let x_cross = prev.x + (p.y - prev.y) / (curr.y - prev.y) * (curr.x - prev.x);
+                if p.x < x_cross {
+                    inside = !inside;
+                }
+            }
+            prev = curr;
+        }
+        inside
+    }
+    fn flip_rotate(&self) -> Polygon<'static> {
         let mut points = self.points();
         let p0 = points.next().unwrap();
         let p1 = points.next().unwrap();
@@ -193,7 +238,7 @@ impl<'a> Polygon<'a> {
 
         Polygon::new(Cow::Owned(buf))
     }
-    fn flip_reflect(&self) -> Polygon {
+    pub(crate) fn flip_reflect(&self) -> Polygon<'static> {
         let mut points = self.points();
         let p0 = points.next().unwrap();
         let p1 = points.next().unwrap();
@@ -204,7 +249,7 @@ impl<'a> Polygon<'a> {
         buf.push(p1);
         for p in points {
             let v = p - p0;
-            let p_compl = p0 - 2.0 * (v - v.onto(axis));
+            let p_compl = p - 2.0 * (v - v.onto(axis));
             buf.push(p_compl);
         }
 
@@ -232,17 +277,17 @@ impl<'a> Polygon<'a> {
     }
 }
 
-struct Matrix {
+pub(crate) struct Matrix {
     coords: [f64; 6],
 }
 
 impl Matrix {
-    fn identity() -> Self {
+    pub(crate) fn identity() -> Self {
         Matrix {
             coords: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
         }
     }
-    fn translate(v: Vect) -> Self {
+    pub(crate) fn translate(v: Vect) -> Self {
         Matrix {
             coords: [1.0, 0.0, 0.0, 1.0, v.x, v.y],
         }
@@ -252,6 +297,16 @@ impl Matrix {
             coords: [v.x, v.y, -v.y, v.x, 0.0, 0.0],
         }
     }
+    pub(crate) fn rotate(angle: f64) -> Self {
+        Matrix {
+            coords: [angle.cos(), angle.sin(), -angle.sin(), angle.cos(), 0.0, 0.0],
+        }
+    }
+    pub(crate) fn scale(sx: f64, sy: f64) -> Self {
+        Matrix {
+            coords: [sx, 0.0, 0.0, sy, 0.0, 0.0],
+        }
+    }
     fn v11(&self) -> f64 {
         self.coords[0]
     }
@@ -274,11 +329,33 @@ impl Matrix {
         self.coords[4]
     }
     fn v23(&self) -> f64 {
-        self.coords[6]
+        self.coords[5]
     }
     fn v33(&self) -> f64 {
         1.0
     }
+    /// Determinant of the 2x2 linear part; the translation doesn't affect it.
+    pub(crate) fn determinant(&self) -> f64 {
+        self.v11() * self.v22() - self.v12() * self.v21()
+    }
+    /// Inverts the 2x2 linear block and back-transforms the translation
+    /// (`t' = -M⁻¹·t`), or `None` if the linear part is singular.
+    pub(crate) fn invert(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let v11 = self.v22() * inv_det;
+        let v12 = -self.v12() * inv_det;
+        let v21 = -self.v21() * inv_det;
+        let v22 = self.v11() * inv_det;
+        let v13 = -(v11 * self.v13() + v12 * self.v23());
+        let v23 = -(v21 * self.v13() + v22 * self.v23());
+        Some(Matrix {
+            coords: [v11, v21, v12, v22, v13, v23],
+        })
+    }
 }
 
 impl std::ops::Mul for Matrix {
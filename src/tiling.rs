@@ -0,0 +1,98 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{Point, Polygon};
+
+/// Reciprocal of the 1e-6 snap grid used to quantize centroids before
+/// hashing, so that reflections landing on the same spot up to float error
+/// collapse to one entry.
+const QUANTIZE: f64 = 1e6;
+
+pub(crate) struct Rect {
+    pub(crate) min: Point,
+    pub(crate) max: Point,
+}
+
+impl Rect {
+    pub(crate) fn contains(&self, p: Point) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+}
+
+pub(crate) struct Tiling;
+
+impl Tiling {
+    /// Flood-fills `bounds` with copies of `seed`, reflecting across each
+    /// edge in turn via `Polygon::flip_reflect`. Only terminates for seeds
+    /// whose reflections actually tile the plane (triangles, quadrilaterals,
+    /// certain hexagons); `bounds` is what keeps the queue finite.
+    pub(crate) fn generate(seed: Polygon<'static>, bounds: Rect) -> Vec<Polygon<'static>> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        let mut tiles = Vec::new();
+
+        seen.insert(quantize(centroid(&seed)));
+        queue.push_back(seed);
+
+        while let Some(poly) = queue.pop_front() {
+            for edge in 0..poly.len() {
+                let neighbor = poly.align(edge as isize).flip_reflect();
+                let c = centroid(&neighbor);
+                if !bounds.contains(c) {
+                    continue;
+                }
+                if seen.insert(quantize(c)) {
+                    queue.push_back(neighbor);
+                }
+            }
+            tiles.push(poly);
+        }
+
+        tiles
+    }
+}
+
+fn centroid(poly: &Polygon) -> Point {
+    let (sum_x, sum_y) = poly
+        .points()
+        .fold((0.0, 0.0), |(sx, sy), p| (sx + p.x, sy + p.y));
+    let n = poly.len() as f64;
+    Point {
+        x: sum_x / n,
+        y: sum_y / n,
+    }
+}
+
+fn quantize(p: Point) -> (i64, i64) {
+    ((p.x * QUANTIZE).round() as i64, (p.y * QUANTIZE).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn unit_square_tiles_a_grid_without_overlap() {
+        let seed = Polygon::new(Cow::Owned(vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 0.0, y: 1.0 },
+        ]));
+        let bounds = Rect {
+            min: Point { x: -3.0, y: -3.0 },
+            max: Point { x: 3.0, y: 3.0 },
+        };
+
+        let tiles = Tiling::generate(seed, bounds);
+        assert_eq!(tiles.len(), 36);
+
+        for tile in &tiles {
+            assert!((tile.signed_area().abs() - 1.0).abs() < 1e-9);
+        }
+        for tile in &tiles {
+            let covering = tiles.iter().filter(|t| t.contains(centroid(tile))).count();
+            assert_eq!(covering, 1);
+        }
+    }
+}